@@ -0,0 +1,205 @@
+// In-process Python bridge backend (feature = "embedded-python"): imports
+// `aya.tauri_bridge` directly via PyO3 instead of spawning it as a
+// subprocess, eliminating process-startup latency and the bundled-executable
+// packaging that `process_bridge::get_python_command` needs. The command
+// API surface matches `process_bridge` exactly so the frontend is
+// unchanged; only the implementation behind it differs.
+
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+
+use pyo3::prelude::*;
+use pyo3::types::PyModule;
+use serde_json::Value;
+use tauri::{AppHandle, State, Window};
+
+// One call into the embedded interpreter: the bridge method to invoke,
+// its JSON params, and where to send the JSON result or error string
+struct PyCall {
+    method: String,
+    params: Value,
+    reply: mpsc::Sender<Result<Value, String>>,
+}
+
+// State struct to manage the embedded interpreter
+pub struct PythonBridgeState {
+    is_running: bool,
+    worker: Option<JoinHandle<()>>,
+    calls: Option<mpsc::Sender<PyCall>>,
+}
+
+impl PythonBridgeState {
+    pub fn new() -> Self {
+        Self {
+            is_running: false,
+            worker: None,
+            calls: None,
+        }
+    }
+}
+
+// Runs on a dedicated OS thread for the lifetime of the interpreter so
+// blocking Python calls (and the GIL) never hold up Tauri's async runtime.
+// Every call is marshalled as a JSON string across the boundary and
+// dispatched through `aya.tauri_bridge.handle_request(method, params_json)`.
+fn run_interpreter(calls: mpsc::Receiver<PyCall>, ready_tx: mpsc::Sender<Result<(), String>>) {
+    let module: Py<PyModule> = match Python::with_gil(|py| PyModule::import(py, "aya.tauri_bridge").map(Into::into)) {
+        Ok(module) => {
+            let _ = ready_tx.send(Ok(()));
+            module
+        }
+        Err(e) => {
+            let _ = ready_tx.send(Err(format!("Failed to import aya.tauri_bridge: {}", e)));
+            return;
+        }
+    };
+
+    for call in calls {
+        let outcome = Python::with_gil(|py| -> PyResult<Value> {
+            let params_json = call.params.to_string();
+            let result_json: String = module
+                .as_ref(py)
+                .call_method1("handle_request", (call.method.as_str(), params_json))?
+                .extract()?;
+            serde_json::from_str(&result_json)
+                .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+        });
+
+        let reply = outcome.map_err(|e| Python::with_gil(|py| e.value(py).to_string()));
+        let _ = call.reply.send(reply);
+    }
+}
+
+// Direct function to start the embedded Python bridge (for auto-start).
+// Spins up the interpreter thread and blocks until the initial import of
+// `aya.tauri_bridge` either succeeds or fails. `ready_timeout_ms` is
+// accepted for API-shape parity with `process_bridge` but unused — the
+// import either resolves immediately or fails, there's no handshake to
+// wait on.
+pub async fn start_python_bridge_direct(
+    state: Arc<Mutex<PythonBridgeState>>,
+    _app_handle: AppHandle,
+    _window: Window,
+    _ready_timeout_ms: Option<u64>,
+) -> Result<String, String> {
+    let mut state_guard = state.lock().unwrap();
+
+    if state_guard.is_running {
+        return Err("Python bridge is already running".to_string());
+    }
+
+    let (call_tx, call_rx) = mpsc::channel::<PyCall>();
+    let (ready_tx, ready_rx) = mpsc::channel::<Result<(), String>>();
+
+    let worker = std::thread::spawn(move || run_interpreter(call_rx, ready_tx));
+
+    ready_rx
+        .recv()
+        .map_err(|_| "Embedded Python interpreter thread exited before becoming ready".to_string())??;
+
+    state_guard.calls = Some(call_tx);
+    state_guard.worker = Some(worker);
+    state_guard.is_running = true;
+
+    Ok("Embedded Python bridge started successfully".to_string())
+}
+
+// Command to start the Python bridge
+#[tauri::command]
+pub async fn start_python_bridge(
+    state: State<'_, Arc<Mutex<PythonBridgeState>>>,
+    app_handle: AppHandle,
+    window: Window,
+    ready_timeout_ms: Option<u64>,
+) -> Result<String, String> {
+    start_python_bridge_direct(Arc::clone(&state.inner()), app_handle, window, ready_timeout_ms).await
+}
+
+// Command to stop the embedded Python bridge: dropping the call channel
+// ends the interpreter thread's `for call in calls` loop. `grace_period_ms`
+// is accepted for API-shape parity with `process_bridge` but unused here —
+// there's no child process to escalate a signal against.
+#[tauri::command]
+pub async fn stop_python_bridge(
+    state: State<'_, Arc<Mutex<PythonBridgeState>>>,
+    _window: Window,
+    _grace_period_ms: Option<u64>,
+) -> Result<String, String> {
+    let mut state_guard = state.lock().unwrap();
+
+    if !state_guard.is_running {
+        return Err("Python bridge is not running".to_string());
+    }
+
+    state_guard.calls = None;
+    if let Some(worker) = state_guard.worker.take() {
+        let _ = worker.join();
+    }
+    state_guard.is_running = false;
+
+    Ok("Embedded Python bridge stopped successfully".to_string())
+}
+
+// Reports whether the embedded interpreter has finished initializing,
+// rather than whether a subprocess is alive
+#[tauri::command]
+pub async fn is_python_bridge_running(
+    state: State<'_, Arc<Mutex<PythonBridgeState>>>,
+) -> Result<bool, String> {
+    let state = state.lock().unwrap();
+    Ok(state.is_running)
+}
+
+// The embedded backend has no subprocess stdout/stderr to buffer; history
+// is always empty. Kept so the command API shape matches `process_bridge`.
+#[tauri::command]
+pub async fn get_python_bridge_log_history(
+    _state: State<'_, Arc<Mutex<PythonBridgeState>>>,
+) -> Result<Vec<Value>, String> {
+    Ok(Vec::new())
+}
+
+// Invoke a bridge method directly in the embedded interpreter and return
+// its JSON result. Python exceptions surface as structured `Err` strings
+// instead of a correlated `response` message. `request_id` is accepted for
+// API-shape parity with `process_bridge` but unused — there's no reader
+// thread to correlate a reply against, the call just returns its result.
+#[tauri::command]
+pub async fn send_to_bridge(
+    state: State<'_, Arc<Mutex<PythonBridgeState>>>,
+    _request_id: u64,
+    method: String,
+    params: Value,
+) -> Result<Value, String> {
+    let calls = {
+        let state_guard = state.lock().unwrap();
+        state_guard
+            .calls
+            .clone()
+            .ok_or_else(|| "Python bridge is not running".to_string())?
+    };
+
+    let (reply_tx, reply_rx) = mpsc::channel();
+    calls
+        .send(PyCall { method, params, reply: reply_tx })
+        .map_err(|_| "Python bridge interpreter thread is not accepting calls".to_string())?;
+
+    tauri::async_runtime::spawn_blocking(move || {
+        reply_rx
+            .recv()
+            .unwrap_or_else(|_| Err("Python bridge interpreter thread closed before responding".to_string()))
+    })
+    .await
+    .map_err(|e| format!("Embedded Python call panicked: {}", e))?
+}
+
+// No subprocess to crash-supervise when the interpreter runs in-process;
+// kept as a no-op so `main.rs` can call it unconditionally regardless of
+// which backend is active.
+pub fn spawn_bridge_supervisor(
+    _state: Arc<Mutex<PythonBridgeState>>,
+    _app_handle: AppHandle,
+    _window: Window,
+) {
+}
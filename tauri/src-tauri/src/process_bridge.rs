@@ -0,0 +1,683 @@
+// Subprocess-backed Python bridge: spawns `python -m aya.tauri_bridge` (or
+// the bundled executable in production) as a child process and talks to it
+// over stdio. This is the default backend; see `embedded_bridge` for the
+// PyO3-based in-process alternative behind the `embedded-python` feature.
+
+use std::collections::{HashMap, VecDeque};
+use std::io::{BufRead, BufReader, Write};
+use std::process::{Command, Stdio};
+use std::sync::{Arc, Mutex};
+use std::env;
+use serde::Serialize;
+use serde_json::Value;
+use tokio::sync::oneshot;
+use tauri::{AppHandle, Manager, State, Window};
+
+// Maximum number of log lines retained for reconnect replay. The buffer is
+// shared by every bridge process and is not cleared on restart.
+const LOG_HISTORY_CAPACITY: usize = 500;
+
+// A single line of bridge output, tagged with its stream and arrival time
+#[derive(Clone, Serialize)]
+pub struct PythonBridgeLogLine {
+    stream: &'static str, // "stdout" or "stderr"
+    line: String,
+    timestamp_ms: u128,
+}
+
+// State struct to manage the Python process
+pub struct PythonBridgeState {
+    process: Option<std::process::Child>,
+    // The child's stdin, kept open so we can write a shutdown message to
+    // it on platforms without a signal-based graceful stop
+    stdin: Option<std::process::ChildStdin>,
+    is_running: bool,
+    // Set for the duration of `start_python_bridge_direct`'s spawn-and-wait
+    // window, before `is_running` goes true. Lets a concurrent start call
+    // be rejected without requiring the lock to be held across that window.
+    starting: bool,
+    // Whether the bridge is supposed to be up right now. Cleared by
+    // `stop_python_bridge` so the supervisor can tell an intentional
+    // shutdown apart from a crash and skip restarting it.
+    should_run: bool,
+    restart_count: u32,
+    last_exit_status: Option<String>,
+    log_history: VecDeque<PythonBridgeLogLine>,
+    // Requests awaiting a correlated `response` message from the bridge,
+    // keyed by the request_id the caller supplied to `send_to_bridge`
+    pending_requests: HashMap<u64, oneshot::Sender<Value>>,
+}
+
+impl PythonBridgeState {
+    pub fn new() -> Self {
+        Self {
+            process: None,
+            stdin: None,
+            is_running: false,
+            starting: false,
+            should_run: false,
+            restart_count: 0,
+            last_exit_status: None,
+            log_history: VecDeque::with_capacity(LOG_HISTORY_CAPACITY),
+            pending_requests: HashMap::new(),
+        }
+    }
+
+    fn push_log_line(&mut self, line: PythonBridgeLogLine) {
+        if self.log_history.len() == LOG_HISTORY_CAPACITY {
+            self.log_history.pop_front();
+        }
+        self.log_history.push_back(line);
+    }
+}
+
+// Supervisor tuning: poll interval, backoff schedule and restart ceiling
+const SUPERVISOR_POLL_INTERVAL_MS: u64 = 1000;
+const INITIAL_RESTART_BACKOFF_MS: u64 = 500;
+const MAX_RESTART_BACKOFF_MS: u64 = 8000;
+const MAX_RESTART_ATTEMPTS: u32 = 10;
+
+// Default time to wait for a graceful exit before escalating to a hard kill
+const DEFAULT_SHUTDOWN_GRACE_MS: u64 = 3000;
+const SHUTDOWN_POLL_INTERVAL_MS: u64 = 100;
+
+// Default time to wait for the `{"event":"ready"}` handshake before
+// giving up on a freshly spawned bridge process
+const DEFAULT_READY_TIMEOUT_MS: u64 = 10_000;
+const READY_POLL_INTERVAL_MS: u64 = 200;
+
+// Ask the child to exit on its own: SIGTERM on Unix, or a shutdown message
+// over stdin elsewhere. Poll `try_wait()` until it exits or `grace_ms`
+// elapses, escalating to a hard `kill()` if it's still alive. Returns
+// whether the exit was graceful.
+fn shutdown_process(process: &mut std::process::Child, _stdin: &mut Option<std::process::ChildStdin>, grace_ms: u64) -> Result<bool, String> {
+    #[cfg(unix)]
+    {
+        let pid = process.id() as libc::pid_t;
+        if unsafe { libc::kill(pid, libc::SIGTERM) } != 0 {
+            println!("=== SIGTERM FAILED: {} ===", std::io::Error::last_os_error());
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        if let Some(child_stdin) = _stdin.as_mut() {
+            if let Err(e) = writeln!(child_stdin, "{{\"type\":\"shutdown\"}}") {
+                println!("=== FAILED TO WRITE SHUTDOWN MESSAGE TO BRIDGE STDIN: {} ===", e);
+            }
+        }
+    }
+
+    let deadline = std::time::Instant::now() + std::time::Duration::from_millis(grace_ms);
+    loop {
+        match process.try_wait() {
+            Ok(Some(_)) => return Ok(true),
+            Ok(None) => {
+                if std::time::Instant::now() >= deadline {
+                    break;
+                }
+                std::thread::sleep(std::time::Duration::from_millis(SHUTDOWN_POLL_INTERVAL_MS));
+            }
+            Err(e) => return Err(format!("Failed to poll Python bridge during shutdown: {}", e)),
+        }
+    }
+
+    println!("=== GRACE PERIOD ELAPSED, ESCALATING TO SIGKILL ===");
+    process.kill().map_err(|e| format!("Failed to kill Python bridge: {}", e))?;
+    Ok(false)
+}
+
+fn now_ms() -> u128 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis())
+        .unwrap_or(0)
+}
+
+// Spawn a reader thread that tags each line from `pipe` with `stream` and
+// forwards it to the frontend as a `python-bridge-log` event, while also
+// appending it to the ring buffer in `state` for reconnect history.
+//
+// This thread locks `state` on every line, so no caller may hold that
+// lock across a wait for this thread to make progress (e.g. waiting on
+// `ready_tx` in `start_python_bridge_direct`) — doing so deadlocks both
+// sides.
+fn spawn_log_reader<R: std::io::Read + Send + 'static>(
+    pipe: R,
+    stream: &'static str,
+    state: Arc<Mutex<PythonBridgeState>>,
+    window: Window,
+) {
+    std::thread::spawn(move || {
+        let reader = BufReader::new(pipe);
+        for line in reader.lines() {
+            let line = match line {
+                Ok(line) => line,
+                Err(_) => break,
+            };
+
+            let log_line = PythonBridgeLogLine {
+                stream,
+                line,
+                timestamp_ms: now_ms(),
+            };
+
+            if let Ok(mut state_guard) = state.lock() {
+                state_guard.push_log_line(log_line.clone());
+            }
+
+            let _ = window.emit("python-bridge-log", &log_line);
+        }
+    });
+}
+
+// Like `spawn_log_reader`, but additionally treats each stdout line as a
+// potential line-delimited JSON protocol message: a `response` resolves
+// the matching entry in `pending_requests`, while a `notification` is
+// forwarded to the frontend as-is. Lines that aren't JSON (or don't match
+// either shape) are only logged, same as any other bridge output.
+//
+// Same locking caveat as `spawn_log_reader`: this thread must acquire
+// `state` to record each line and to fire `ready_tx`, so no caller may
+// hold that lock while waiting on this thread.
+fn spawn_stdout_reader<R: std::io::Read + Send + 'static>(
+    pipe: R,
+    state: Arc<Mutex<PythonBridgeState>>,
+    window: Window,
+    ready_tx: std::sync::mpsc::Sender<()>,
+) {
+    std::thread::spawn(move || {
+        let reader = BufReader::new(pipe);
+        for line in reader.lines() {
+            let line = match line {
+                Ok(line) => line,
+                Err(_) => break,
+            };
+
+            let log_line = PythonBridgeLogLine {
+                stream: "stdout",
+                line: line.clone(),
+                timestamp_ms: now_ms(),
+            };
+
+            if let Ok(mut state_guard) = state.lock() {
+                state_guard.push_log_line(log_line.clone());
+            }
+            let _ = window.emit("python-bridge-log", &log_line);
+
+            let message: Value = match serde_json::from_str(&line) {
+                Ok(message) => message,
+                Err(_) => continue,
+            };
+
+            match message.get("type").and_then(Value::as_str) {
+                Some("response") => {
+                    let request_id = message.get("request_id").and_then(Value::as_u64);
+                    if let Some(request_id) = request_id {
+                        let sender = state.lock().unwrap().pending_requests.remove(&request_id);
+                        if let Some(sender) = sender {
+                            let _ = sender.send(message);
+                        }
+                    }
+                }
+                Some("notification") => {
+                    let _ = window.emit("python-bridge-notification", &message);
+                }
+                _ => {}
+            }
+
+            if message.get("event").and_then(Value::as_str) == Some("ready") {
+                let _ = ready_tx.send(());
+            }
+        }
+    });
+}
+
+// Collect the stderr lines seen so far, for error messages when the
+// bridge never becomes ready. Takes its own brief lock rather than a
+// pre-acquired guard, since the readiness wait that calls this must not
+// hold `state` for the reader threads to make progress (see
+// `start_python_bridge_direct`).
+fn recent_stderr(state: &Arc<Mutex<PythonBridgeState>>) -> String {
+    state
+        .lock()
+        .unwrap()
+        .log_history
+        .iter()
+        .filter(|line| line.stream == "stderr")
+        .map(|line| line.line.as_str())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// Direct function to start the Python bridge (for auto-start).
+// `ready_timeout_ms` (defaults to `DEFAULT_READY_TIMEOUT_MS`) bounds how
+// long we wait for the readiness handshake before giving up.
+//
+// Must not hold `state`'s lock across the reader-thread spawn or the
+// readiness wait below: the stdout/stderr reader threads need that same
+// lock on every line to update `log_history` and resolve `ready_tx`, so
+// holding it here would deadlock the bridge's own startup. `starting` is
+// set under a brief lock as a reservation instead, to keep concurrent
+// start calls from racing each other while no lock is held.
+pub async fn start_python_bridge_direct(
+    state: Arc<Mutex<PythonBridgeState>>,
+    app_handle: AppHandle,
+    window: Window,
+    ready_timeout_ms: Option<u64>,
+) -> Result<String, String> {
+    println!("=== START_PYTHON_BRIDGE_DIRECT CALLED ===");
+
+    {
+        let mut state_guard = state.lock().unwrap();
+        if state_guard.is_running || state_guard.starting {
+            println!("=== BRIDGE ALREADY RUNNING ===");
+            return Err("Python bridge is already running".to_string());
+        }
+        state_guard.starting = true;
+    }
+
+    // Get the path to the Python executable (bundled or installed)
+    println!("=== GETTING PYTHON COMMAND ===");
+    let (cmd, args) = match get_python_command(&app_handle) {
+        Ok(result) => result,
+        Err(e) => {
+            state.lock().unwrap().starting = false;
+            return Err(e);
+        }
+    };
+    println!("=== PYTHON COMMAND: {} ARGS: {:?} ===", cmd, args);
+
+    // Build the command
+    let mut command = Command::new(cmd);
+
+    // Add arguments if any
+    if let Some(args_vec) = args {
+        command.args(args_vec);
+    }
+
+    // Set environment variables if needed
+    if let Ok(api_key) = env::var("GEMINI_API_KEY") {
+        command.env("GEMINI_API_KEY", api_key);
+    }
+
+    // Pipe stdin (for graceful-shutdown messages on platforms without
+    // signals) and stdout/stderr so we can stream them to the frontend
+    // instead of letting them vanish into the terminal
+    command.stdin(Stdio::piped());
+    command.stdout(Stdio::piped());
+    command.stderr(Stdio::piped());
+
+    // Set working directory to the project root
+    if let Some(current_dir) = std::env::current_dir().ok() {
+        println!("=== SETTING WORKING DIRECTORY: {:?} ===", current_dir);
+        command.current_dir(current_dir);
+    }
+
+    // Start the process
+    println!("=== SPAWNING PYTHON PROCESS ===");
+    match command.spawn() {
+        Ok(mut process) => {
+            println!("=== PYTHON BRIDGE PROCESS STARTED SUCCESSFULLY ===");
+            println!("Process ID: {}", process.id());
+
+            // Stream stdout/stderr incrementally on dedicated threads so
+            // neither stream blocks the other while the process runs
+            let (ready_tx, ready_rx) = std::sync::mpsc::channel::<()>();
+            if let Some(stdout) = process.stdout.take() {
+                spawn_stdout_reader(stdout, Arc::clone(&state), window.clone(), ready_tx);
+            }
+            if let Some(stderr) = process.stderr.take() {
+                spawn_log_reader(stderr, "stderr", Arc::clone(&state), window.clone());
+            }
+
+            // Wait for the bridge to announce itself with a `{"event":
+            // "ready"}` line rather than guessing with a fixed sleep, so we
+            // don't report success before it's actually serving
+            let ready_timeout = ready_timeout_ms.unwrap_or(DEFAULT_READY_TIMEOUT_MS);
+            let deadline = std::time::Instant::now() + std::time::Duration::from_millis(ready_timeout);
+            loop {
+                match process.try_wait() {
+                    Ok(Some(status)) => {
+                        println!("=== PYTHON PROCESS EXITED BEFORE BECOMING READY: {:?} ===", status);
+                        state.lock().unwrap().starting = false;
+                        return Err(format!(
+                            "Python bridge exited before becoming ready (status: {:?}): {}",
+                            status,
+                            recent_stderr(&state)
+                        ));
+                    }
+                    Ok(None) => {}
+                    Err(e) => println!("=== ERROR CHECKING PYTHON PROCESS STATUS: {} ===", e),
+                }
+
+                let remaining = deadline.saturating_duration_since(std::time::Instant::now());
+                if remaining.is_zero() {
+                    let _ = process.kill();
+                    state.lock().unwrap().starting = false;
+                    return Err(format!(
+                        "Python bridge did not become ready within {}ms: {}",
+                        ready_timeout,
+                        recent_stderr(&state)
+                    ));
+                }
+
+                match ready_rx.recv_timeout(remaining.min(std::time::Duration::from_millis(READY_POLL_INTERVAL_MS))) {
+                    Ok(()) => {
+                        println!("=== PYTHON BRIDGE READINESS HANDSHAKE RECEIVED ===");
+                        break;
+                    }
+                    Err(std::sync::mpsc::RecvTimeoutError::Timeout) => continue,
+                    Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => {
+                        state.lock().unwrap().starting = false;
+                        return Err(format!(
+                            "Python bridge stdout closed before becoming ready: {}",
+                            recent_stderr(&state)
+                        ));
+                    }
+                }
+            }
+
+            // Only now, with the bridge confirmed ready, re-acquire the
+            // lock to persist the running process and flip it visible to
+            // `is_python_bridge_running`/the supervisor.
+            let mut state_guard = state.lock().unwrap();
+            state_guard.starting = false;
+            state_guard.stdin = process.stdin.take();
+            state_guard.process = Some(process);
+            state_guard.is_running = true;
+            state_guard.should_run = true;
+            drop(state_guard);
+
+            // Emit event to frontend using the window object
+            let _ = window.emit("python-bridge-status", true);
+
+            Ok("Python bridge started successfully".to_string())
+        },
+        Err(e) => {
+            println!("=== FAILED TO START PYTHON PROCESS: {} ===", e);
+            state.lock().unwrap().starting = false;
+            Err(format!("Failed to start Python bridge: {}", e))
+        }
+    }
+}
+
+// Watches the running Python process and restarts it with exponential
+// backoff when it exits unexpectedly. Restarts are skipped if
+// `should_run` was cleared by an intentional `stop_python_bridge` call,
+// and give up after `MAX_RESTART_ATTEMPTS` with a terminal error event.
+pub fn spawn_bridge_supervisor(
+    state: Arc<Mutex<PythonBridgeState>>,
+    app_handle: AppHandle,
+    window: Window,
+) {
+    std::thread::spawn(move || loop {
+        std::thread::sleep(std::time::Duration::from_millis(SUPERVISOR_POLL_INTERVAL_MS));
+
+        let crash_status = {
+            let mut state_guard = state.lock().unwrap();
+            if !state_guard.is_running || !state_guard.should_run {
+                None
+            } else {
+                match state_guard.process.as_mut().map(|p| p.try_wait()) {
+                    Some(Ok(Some(status))) => {
+                        state_guard.process = None;
+                        state_guard.is_running = false;
+                        state_guard.last_exit_status = Some(format!("{:?}", status));
+                        // Drop any in-flight send_to_bridge senders so their
+                        // awaiting callers get "closed before responding"
+                        // instead of hanging forever on request_ids the
+                        // restarted process will never answer.
+                        state_guard.pending_requests.clear();
+                        Some(status)
+                    }
+                    _ => None,
+                }
+            }
+        };
+
+        let status = match crash_status {
+            Some(status) => status,
+            None => continue,
+        };
+
+        println!("=== PYTHON BRIDGE CRASHED UNEXPECTEDLY: {:?} ===", status);
+        let _ = window.emit("python-bridge-status", false);
+
+        let mut backoff_ms = INITIAL_RESTART_BACKOFF_MS;
+        let mut attempts: u32 = 0;
+
+        loop {
+            if !state.lock().unwrap().should_run {
+                println!("=== BRIDGE RESTART SUPPRESSED: STOP WAS REQUESTED ===");
+                break;
+            }
+
+            if attempts >= MAX_RESTART_ATTEMPTS {
+                println!("=== PYTHON BRIDGE RESTART GIVING UP AFTER {} ATTEMPTS ===", attempts);
+                let _ = window.emit(
+                    "python-bridge-fatal",
+                    format!("Python bridge failed to stay up after {} restart attempts", attempts),
+                );
+                break;
+            }
+
+            attempts += 1;
+            println!("=== RESTARTING PYTHON BRIDGE (ATTEMPT {}) IN {}ms ===", attempts, backoff_ms);
+            std::thread::sleep(std::time::Duration::from_millis(backoff_ms));
+
+            state.lock().unwrap().restart_count += 1;
+
+            let restart_result = tauri::async_runtime::block_on(start_python_bridge_direct(
+                Arc::clone(&state),
+                app_handle.clone(),
+                window.clone(),
+                None,
+            ));
+
+            match restart_result {
+                Ok(_) => {
+                    println!("=== PYTHON BRIDGE RESTARTED SUCCESSFULLY ===");
+                    break;
+                }
+                Err(e) => {
+                    println!("=== PYTHON BRIDGE RESTART FAILED: {} ===", e);
+                    backoff_ms = (backoff_ms * 2).min(MAX_RESTART_BACKOFF_MS);
+                }
+            }
+        }
+    });
+}
+
+// Internal function to start the Python bridge (for commands)
+async fn start_python_bridge_internal(
+    state: State<'_, Arc<Mutex<PythonBridgeState>>>,
+    app_handle: AppHandle,
+    window: Window,
+    ready_timeout_ms: Option<u64>,
+) -> Result<String, String> {
+    println!("=== START_PYTHON_BRIDGE_INTERNAL CALLED ===");
+    // Use the direct function with the Arc from the state
+    start_python_bridge_direct(Arc::clone(&state.inner()), app_handle, window, ready_timeout_ms).await
+}
+
+// Command to start the Python bridge. `ready_timeout_ms` (defaults to
+// `DEFAULT_READY_TIMEOUT_MS`) bounds how long to wait for the readiness
+// handshake before giving up.
+#[tauri::command]
+pub async fn start_python_bridge(
+    state: State<'_, Arc<Mutex<PythonBridgeState>>>,
+    app_handle: AppHandle,
+    window: Window,
+    ready_timeout_ms: Option<u64>,
+) -> Result<String, String> {
+    println!("=== START_PYTHON_BRIDGE COMMAND CALLED ===");
+    start_python_bridge_internal(state, app_handle, window, ready_timeout_ms).await
+}
+
+// Command to stop the Python bridge. `grace_period_ms` (defaults to
+// `DEFAULT_SHUTDOWN_GRACE_MS`) is how long to wait for a graceful exit
+// before escalating to a hard kill.
+#[tauri::command]
+pub async fn stop_python_bridge(
+    state: State<'_, Arc<Mutex<PythonBridgeState>>>,
+    window: Window,
+    grace_period_ms: Option<u64>,
+) -> Result<String, String> {
+    // Take the process/stdin out and drop the lock before running
+    // shutdown_process below: that call polls try_wait() for up to
+    // grace_period_ms, during which the stdout/stderr reader threads and
+    // the supervisor's poll loop all need this same lock to make
+    // progress. Holding it here would risk the child blocking on a full
+    // stdout/stderr pipe with nothing draining it, turning a graceful
+    // shutdown into a forced SIGKILL (see fix fd00916 for the same
+    // discipline in start_python_bridge_direct).
+    let (mut process, mut stdin) = {
+        let mut state_guard = state.lock().unwrap();
+
+        if !state_guard.is_running {
+            return Err("Python bridge is not running".to_string());
+        }
+
+        // Mark this as an intentional shutdown first so the supervisor
+        // doesn't race the kill and try to restart the bridge we're
+        // about to stop
+        state_guard.should_run = false;
+
+        match state_guard.process.take() {
+            Some(process) => (process, state_guard.stdin.take()),
+            None => {
+                state_guard.is_running = false;
+                return Ok("Python bridge was not running".to_string());
+            }
+        }
+    };
+
+    let grace_ms = grace_period_ms.unwrap_or(DEFAULT_SHUTDOWN_GRACE_MS);
+    let result = shutdown_process(&mut process, &mut stdin, grace_ms);
+
+    let mut state_guard = state.lock().unwrap();
+    match result {
+        Ok(graceful) => {
+            println!("Stopped Python bridge process (graceful: {})", graceful);
+            state_guard.is_running = false;
+            state_guard.pending_requests.clear();
+
+            // Emit events to frontend using the window object
+            let _ = window.emit("python-bridge-status", false);
+            let _ = window.emit("python-bridge-shutdown", graceful);
+
+            Ok(if graceful {
+                "Python bridge stopped gracefully".to_string()
+            } else {
+                "Python bridge did not exit in time and was forcibly killed".to_string()
+            })
+        },
+        Err(e) => {
+            // Put the process back; it's still running so the
+            // supervisor should keep watching it
+            state_guard.process = Some(process);
+            state_guard.stdin = stdin;
+            state_guard.should_run = true;
+            Err(format!("Failed to stop Python bridge: {}", e))
+        }
+    }
+}
+
+// Helper function to get the Python command
+fn get_python_command(app_handle: &AppHandle) -> Result<(String, Option<Vec<String>>), String> {
+    // Check if we're in production (bundled) or development mode
+    if cfg!(debug_assertions) {
+        // In development, use the system Python
+        #[cfg(target_os = "windows")]
+        {
+            Ok(("python".to_string(), Some(vec!["-m".to_string(), "aya.tauri_bridge".to_string()])))
+        }
+        #[cfg(not(target_os = "windows"))]
+        {
+            Ok(("python3".to_string(), Some(vec!["-m".to_string(), "aya.tauri_bridge".to_string()])))
+        }
+    } else {
+        // In production, use the bundled executable
+        let app_dir = app_handle.path_resolver().app_data_dir().ok_or("Failed to get app directory")?;
+
+        #[cfg(target_os = "windows")]
+        {
+            let bridge_path = app_dir.join("resources").join("aya_bridge.exe");
+            Ok((bridge_path.to_string_lossy().to_string(), None))
+        }
+        #[cfg(target_os = "macos")]
+        {
+            let bridge_path = app_dir.join("Resources").join("aya_bridge");
+            Ok((bridge_path.to_string_lossy().to_string(), None))
+        }
+        #[cfg(target_os = "linux")]
+        {
+            let bridge_path = app_dir.join("resources").join("aya_bridge");
+            Ok((bridge_path.to_string_lossy().to_string(), None))
+        }
+        #[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
+        {
+            Err("Unsupported platform".to_string())
+        }
+    }
+}
+
+// Check if Python bridge is running
+#[tauri::command]
+pub async fn is_python_bridge_running(
+    state: State<'_, Arc<Mutex<PythonBridgeState>>>,
+) -> Result<bool, String> {
+    let state = state.lock().unwrap();
+    Ok(state.is_running)
+}
+
+// Fetch the last N lines of bridge stdout/stderr, for the in-app log
+// console to replay history after a reconnect
+#[tauri::command]
+pub async fn get_python_bridge_log_history(
+    state: State<'_, Arc<Mutex<PythonBridgeState>>>,
+) -> Result<Vec<PythonBridgeLogLine>, String> {
+    let state = state.lock().unwrap();
+    Ok(state.log_history.iter().cloned().collect())
+}
+
+// Send a request to the Python bridge over its stdin and await the
+// correlated `response` the stdout reader resolves, turning the bridge
+// into a proper bidirectional RPC endpoint. `request_id` is supplied by
+// the caller and must be unique among in-flight requests.
+#[tauri::command]
+pub async fn send_to_bridge(
+    state: State<'_, Arc<Mutex<PythonBridgeState>>>,
+    request_id: u64,
+    method: String,
+    params: Value,
+) -> Result<Value, String> {
+    let receiver = {
+        let mut state_guard = state.lock().unwrap();
+
+        if !state_guard.is_running {
+            return Err("Python bridge is not running".to_string());
+        }
+
+        let request = serde_json::json!({
+            "type": "request",
+            "request_id": request_id,
+            "method": method,
+            "params": params,
+        });
+
+        let stdin = state_guard
+            .stdin
+            .as_mut()
+            .ok_or("Python bridge stdin is not available")?;
+        writeln!(stdin, "{}", request).map_err(|e| format!("Failed to write to Python bridge: {}", e))?;
+
+        let (sender, receiver) = oneshot::channel();
+        state_guard.pending_requests.insert(request_id, sender);
+        receiver
+    };
+
+    receiver
+        .await
+        .map_err(|_| "Python bridge closed before responding".to_string())
+}